@@ -0,0 +1,112 @@
+use crate::{maybe_contains::MaybeContains, Cons, Nil};
+
+/// A trait splitting a unique reference to `Self` into disjoint mutable references, one for
+/// each type listed in `Keys`.
+///
+/// This is sound because every type in a map occurs at most once, so requesting pairwise
+/// distinct `Keys` guarantees the returned references don't alias. Requesting a type twice, or a
+/// type `Self` doesn't contain, fails to compile rather than panicking.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Disjoint<'a, Keys> {
+    /// A cons-list of the same shape as `Keys`, holding `&'a mut` items.
+    type Output;
+
+    /// Splits `self` into disjoint mutable references, one for each type in `Keys`.
+    fn disjoint(&'a mut self) -> Self::Output;
+}
+
+impl<'a> Disjoint<'a, Nil> for Nil {
+    type Output = Nil;
+
+    fn disjoint(&'a mut self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, H, T, Keys> Disjoint<'a, Keys> for Cons<H, T>
+where
+    H: 'static,
+    Keys: MaybeContains<H>,
+    Self: DisjointStep<'a, { <Keys as MaybeContains<H>>::CONTAINS }, Keys>,
+{
+    type Output = <Self as DisjointStep<'a, { <Keys as MaybeContains<H>>::CONTAINS }, Keys>>::Output;
+
+    fn disjoint(&'a mut self) -> Self::Output {
+        DisjointStep::disjoint_step(self)
+    }
+}
+
+#[doc(hidden)]
+pub trait DisjointStep<'a, const HEAD: bool, Keys> {
+    type Output;
+
+    fn disjoint_step(&'a mut self) -> Self::Output;
+}
+
+// `H` is one of the requested `Keys`: take `&mut head` and recurse the tail with `H` removed
+// from `Keys`.
+impl<'a, H, T, Keys> DisjointStep<'a, true, Keys> for Cons<H, T>
+where
+    H: 'static,
+    Keys: MaybeContains<H, CONTAINS = true>,
+    T: Disjoint<'a, Keys::Removed>,
+{
+    type Output = Cons<&'a mut H, <T as Disjoint<'a, Keys::Removed>>::Output>;
+
+    fn disjoint_step(&'a mut self) -> Self::Output {
+        let Self { head, tail } = self;
+        Cons {
+            head,
+            tail: tail.disjoint(),
+        }
+    }
+}
+
+// `H` isn't requested: leave it untouched and recurse the tail with `Keys` unchanged.
+impl<'a, H, T, Keys> DisjointStep<'a, false, Keys> for Cons<H, T>
+where
+    T: Disjoint<'a, Keys>,
+{
+    type Output = <T as Disjoint<'a, Keys>>::Output;
+
+    fn disjoint_step(&'a mut self) -> Self::Output {
+        let Self { tail, .. } = self;
+        tail.disjoint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ty = Cons<u8, Cons<u16, Cons<u32, Nil>>>;
+    type Keys = Cons<u32, Cons<u8, Nil>>;
+
+    static_assertions::assert_impl_all!(Ty: Disjoint<'static, Keys>);
+
+    // Requesting the same type twice would alias `&mut u8` with itself, so this must not
+    // type-check.
+    static_assertions::assert_not_impl_any!(Ty: Disjoint<'static, Cons<u8, Cons<u8, Nil>>>);
+
+    #[test]
+    fn get_disjoint_mut() {
+        let mut map: Ty = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Cons {
+                    head: 3_u32,
+                    tail: Nil,
+                },
+            },
+        };
+
+        let disjoint: Cons<&mut u32, Cons<&mut u8, Nil>> = map.disjoint();
+        *disjoint.head += 10;
+        *disjoint.tail.head += 1;
+
+        assert_eq!(2_u8, map.head);
+        assert_eq!(13_u32, map.tail.tail.head);
+    }
+}
@@ -0,0 +1,180 @@
+use core::marker::PhantomData;
+
+use crate::{contains::Contains, maybe_contains::MaybeContains, missing::Missing, TyghtMap};
+
+/// A trait selecting between an [`OccupiedEntry`] or a [`VacantEntry`] for `Item`, resolved at
+/// compile time from [`MaybeContains::CONTAINS`].
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Entry<Item>: MaybeContains<Item> {
+    /// [`OccupiedEntry<Item, Self>`] when `Item` is present, [`VacantEntry<Item, Self>`]
+    /// otherwise.
+    type EntryType;
+
+    /// Returns the entry for `Item`.
+    fn entry(self) -> Self::EntryType;
+}
+
+impl<Item, S> Entry<Item> for S
+where
+    S: MaybeContains<Item>,
+    S: SelectEntry<{ <S as MaybeContains<Item>>::CONTAINS }, Item>,
+{
+    type EntryType = <S as SelectEntry<{ <S as MaybeContains<Item>>::CONTAINS }, Item>>::Output;
+
+    fn entry(self) -> Self::EntryType {
+        SelectEntry::select(self)
+    }
+}
+
+#[doc(hidden)]
+pub trait SelectEntry<const CONTAINS: bool, Item> {
+    type Output;
+
+    fn select(self) -> Self::Output;
+}
+
+impl<Item, S> SelectEntry<true, Item> for S
+where
+    S: Contains<Item>,
+{
+    type Output = OccupiedEntry<Item, S>;
+
+    fn select(self) -> Self::Output {
+        OccupiedEntry {
+            map: TyghtMap(self),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Item, S> SelectEntry<false, Item> for S
+where
+    S: Missing<Item>,
+{
+    type Output = VacantEntry<Item, S>;
+
+    fn select(self) -> Self::Output {
+        VacantEntry {
+            map: TyghtMap(self),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// An entry for an `Item` known to be present in `S`.
+pub struct OccupiedEntry<Item, S> {
+    map: TyghtMap<S>,
+    _marker: PhantomData<Item>,
+}
+
+impl<Item, S> core::fmt::Debug for OccupiedEntry<Item, S>
+where
+    TyghtMap<S>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OccupiedEntry").field("map", &self.map).finish()
+    }
+}
+
+// There is deliberately no `into_mut`, unlike `std`'s `Entry` APIs: `OccupiedEntry` owns its
+// `TyghtMap<S>` by value rather than borrowing it, so there is no caller-held map for a returned
+// `&'a mut Item` to borrow from without the entry self-referencing its own owned field. The same
+// constraint is why `get_or_insert_with` returns the post-insertion map rather than a reference
+// to the value (see `TyghtMap::get_or_insert_with`).
+impl<Item, S> OccupiedEntry<Item, S>
+where
+    S: Contains<Item>,
+{
+    /// Returns a reference to the entry's value.
+    pub fn get(&self) -> &Item {
+        self.map.get()
+    }
+
+    /// Returns a mutable reference to the entry's value.
+    pub fn get_mut(&mut self) -> &mut Item {
+        self.map.get_mut()
+    }
+
+    /// Applies `f` to the entry's value, then returns the entry.
+    pub fn and_modify(mut self, f: impl FnOnce(&mut Item)) -> Self {
+        f(self.get_mut());
+        self
+    }
+
+    /// Removes the value, returning it alongside the post-removal map.
+    pub fn remove(self) -> (Item, TyghtMap<S::Removed>) {
+        self.map.remove()
+    }
+
+    /// Returns the map unchanged, since the value is already present.
+    pub fn or_insert(self, default: Item) -> TyghtMap<S::Inserted> {
+        self.map.get_or_insert_with(|| default)
+    }
+
+    /// Returns the map unchanged, since the value is already present.
+    pub fn or_insert_with(self, default: impl FnOnce() -> Item) -> TyghtMap<S::Inserted> {
+        self.map.get_or_insert_with(default)
+    }
+}
+
+/// An entry for an `Item` known to be absent from `S`.
+pub struct VacantEntry<Item, S> {
+    map: TyghtMap<S>,
+    _marker: PhantomData<Item>,
+}
+
+impl<Item, S> core::fmt::Debug for VacantEntry<Item, S>
+where
+    TyghtMap<S>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VacantEntry").field("map", &self.map).finish()
+    }
+}
+
+impl<Item, S> VacantEntry<Item, S>
+where
+    S: Missing<Item>,
+{
+    /// Inserts `value`, returning the post-insertion map.
+    pub fn insert(self, value: Item) -> TyghtMap<S::Inserted> {
+        self.map.insert(value)
+    }
+
+    /// Inserts `default`, returning the post-insertion map.
+    pub fn or_insert(self, default: Item) -> TyghtMap<S::Inserted> {
+        self.insert(default)
+    }
+
+    /// Inserts the result of `default`, returning the post-insertion map.
+    pub fn or_insert_with(self, default: impl FnOnce() -> Item) -> TyghtMap<S::Inserted> {
+        self.insert(default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cons, Nil};
+
+    type Ty = Cons<u8, Nil>;
+
+    static_assertions::assert_impl_all!(Ty: Entry<u8, EntryType = OccupiedEntry<u8, Ty>>);
+    static_assertions::assert_impl_all!(Ty: Entry<u16, EntryType = VacantEntry<u16, Ty>>);
+
+    #[test]
+    fn occupied_or_insert() {
+        let map = TyghtMap::new().insert(1_u8);
+        let map = map.entry::<u8>().or_insert(2);
+        assert_eq!(1_u8, *map.get());
+    }
+
+    #[test]
+    fn vacant_or_insert() {
+        let map = TyghtMap::new().insert(1_u8);
+        let map = map.entry::<u16>().or_insert(2);
+        assert_eq!(1_u8, *map.get());
+        assert_eq!(2_u16, *map.get());
+    }
+}
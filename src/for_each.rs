@@ -0,0 +1,69 @@
+use core::marker::Unsize;
+
+use crate::{Cons, Nil};
+
+/// A trait allowing every element of `Self` to be visited as `&Dyn`.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait ForEach<Dyn: ?Sized> {
+    /// Calls `f` with every element, coerced to `&Dyn`.
+    fn for_each(&self, f: &mut dyn FnMut(&Dyn));
+
+    /// Calls `f` with every element, coerced to `&mut Dyn`.
+    fn for_each_mut(&mut self, f: &mut dyn FnMut(&mut Dyn));
+}
+
+impl<Dyn: ?Sized> ForEach<Dyn> for Nil {
+    fn for_each(&self, _f: &mut dyn FnMut(&Dyn)) {}
+
+    fn for_each_mut(&mut self, _f: &mut dyn FnMut(&mut Dyn)) {}
+}
+
+impl<Dyn, H, T> ForEach<Dyn> for Cons<H, T>
+where
+    Dyn: ?Sized,
+    H: Unsize<Dyn>,
+    T: ForEach<Dyn>,
+{
+    fn for_each(&self, f: &mut dyn FnMut(&Dyn)) {
+        f(&self.head);
+        self.tail.for_each(f);
+    }
+
+    fn for_each_mut(&mut self, f: &mut dyn FnMut(&mut Dyn)) {
+        f(&mut self.head);
+        self.tail.for_each_mut(f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Debug;
+
+    use super::*;
+
+    type Ty = Cons<u8, Cons<u16, Cons<u32, Nil>>>;
+
+    static_assertions::assert_impl_all!(Ty: ForEach<dyn Debug>);
+
+    #[test]
+    fn for_each() {
+        let mut map: Ty = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Cons {
+                    head: 3_u32,
+                    tail: Nil,
+                },
+            },
+        };
+
+        let mut count = 0;
+        ForEach::<dyn Debug>::for_each(&map, &mut |_| count += 1);
+        assert_eq!(count, 3);
+
+        ForEach::<dyn Debug>::for_each_mut(&mut map, &mut |_| count += 1);
+        assert_eq!(count, 6);
+    }
+}
@@ -0,0 +1,127 @@
+extern crate alloc;
+
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{any::Any, intrinsics::type_id};
+
+use crate::{missing::Missing, Contains, Nil, TyghtMap};
+
+/// A [`TyghtMap`] paired with a dynamic, `TypeId`-keyed fallback store.
+///
+/// The static half keeps the zero-overhead, compile-time-checked guarantees of [`TyghtMap`].
+/// The dynamic half accepts any `'static` type, at the cost of a heap allocation per entry and a
+/// runtime lookup, for cases where the set of types isn't known until runtime or exceeds the
+/// static map's arity. Prefer the static methods ([`insert`](HybridMap::insert),
+/// [`get`](HybridMap::get), ...) whenever the type is known at compile time.
+pub struct HybridMap<S> {
+    static_map: TyghtMap<S>,
+    dynamic: BTreeMap<u128, Box<dyn Any>>,
+}
+
+impl<S> core::fmt::Debug for HybridMap<S>
+where
+    TyghtMap<S>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HybridMap")
+            .field("static_map", &self.static_map)
+            .field("dynamic_len", &self.dynamic.len())
+            .finish()
+    }
+}
+
+impl HybridMap<Nil> {
+    /// Constructs an empty hybrid map.
+    pub fn new() -> Self {
+        Self {
+            static_map: TyghtMap::new(),
+            dynamic: BTreeMap::new(),
+        }
+    }
+}
+
+impl<S> HybridMap<S> {
+    /// Inserts a value whose type is known at compile time into the static half.
+    pub fn insert<T>(self, item: T) -> HybridMap<S::Inserted>
+    where
+        S: Missing<T>,
+    {
+        HybridMap {
+            static_map: self.static_map.insert(item),
+            dynamic: self.dynamic,
+        }
+    }
+
+    /// Returns a reference to a value whose type is known at compile time.
+    pub fn get<T>(&self) -> &T
+    where
+        S: Contains<T>,
+    {
+        self.static_map.get()
+    }
+
+    /// Returns a mutable reference to a value whose type is known at compile time.
+    pub fn get_mut<T>(&mut self) -> &mut T
+    where
+        S: Contains<T>,
+    {
+        self.static_map.get_mut()
+    }
+
+    /// Inserts a value into the dynamic half, keyed by its [`TypeId`](core::any::TypeId).
+    ///
+    /// Returns the previous value of the same type, if any.
+    pub fn insert_dynamic<T: Any>(&mut self, item: T) -> Option<Box<T>> {
+        self.dynamic
+            .insert(type_id::<T>(), Box::new(item))
+            .map(|prev| prev.downcast().unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Returns a reference to a value previously stored in the dynamic half.
+    pub fn get_dynamic<T: Any>(&self) -> Option<&T> {
+        self.dynamic.get(&type_id::<T>())?.downcast_ref()
+    }
+
+    /// Returns a mutable reference to a value previously stored in the dynamic half.
+    pub fn get_dynamic_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.dynamic.get_mut(&type_id::<T>())?.downcast_mut()
+    }
+
+    /// Removes a value previously stored in the dynamic half.
+    pub fn remove_dynamic<T: Any>(&mut self) -> Option<Box<T>> {
+        self.dynamic
+            .remove(&type_id::<T>())
+            .map(|item| item.downcast().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+impl Default for HybridMap<Nil> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use super::*;
+
+    #[test]
+    fn static_and_dynamic() {
+        let mut map = HybridMap::new().insert(1_u32);
+        assert_eq!(1_u32, *map.get());
+
+        assert_eq!(None, map.insert_dynamic("hey".to_string()));
+        assert_eq!(Some(&"hey".to_string()), map.get_dynamic::<String>());
+
+        *map.get_dynamic_mut::<String>().unwrap() += ", world!";
+        assert_eq!(
+            Some(&"hey, world!".to_string()),
+            map.get_dynamic::<String>()
+        );
+
+        let removed = map.remove_dynamic::<String>();
+        assert_eq!(Some(Box::new("hey, world!".to_string())), removed);
+        assert_eq!(None, map.get_dynamic::<String>());
+    }
+}
@@ -2,8 +2,10 @@
     generic_const_exprs,
     core_intrinsics,
     associated_const_equality,
-    const_type_id
+    const_type_id,
+    unsize
 )]
+#![cfg_attr(feature = "serde", feature(trait_upcasting))]
 #![allow(incomplete_features)]
 #![deny(missing_debug_implementations, missing_docs)]
 #![forbid(unsafe_code)]
@@ -67,8 +69,35 @@
 //!     - [`try_get`](TyghtMap::try_get)
 //!     - [`try_get_mut`](TyghtMap::try_get_mut)
 //!     - [`try_remove`](TyghtMap::try_remove)
+//!     - [`get_or_insert_with`](TyghtMap::get_or_insert_with)
 //! - [`Missing<T>`](Missing) is implemented on `S` when it doesn't contain `T` allowing:
 //!     - [`insert`](TyghtMap::insert)
+//! - [`Union<Rhs>`](Union) is implemented on `S` for any `Rhs`, allowing two maps to be combined:
+//!     - [`merge`](TyghtMap::merge)
+//!     - [`merge_replace`](TyghtMap::merge_replace)
+//! - [`ForEach<Dyn>`](ForEach) is implemented on `S` when every element coerces to `&Dyn`, allowing:
+//!     - [`for_each`](TyghtMap::for_each)
+//!     - [`for_each_mut`](TyghtMap::for_each_mut)
+//! - [`Split<Sub>`](Split)/[`Project<'_, Sub>`](Project) are implemented on `S` when it contains
+//! every type in `Sub`, allowing:
+//!     - [`split`](TyghtMap::split)
+//!     - [`project`](TyghtMap::project)
+//! - [`Entry<T>`](Entry) is implemented on `S` for any `T`, allowing:
+//!     - [`entry`](TyghtMap::entry)
+//! - [`Walk`](Walk) is implemented on every `S`, allowing generic visitors ([`Visitor`],
+//! [`VisitorMut`], [`FnVisitor`]) over all elements:
+//!     - [`walk`](TyghtMap::walk)
+//!     - [`walk_mut`](TyghtMap::walk_mut)
+//!     - [`walk_into`](TyghtMap::walk_into)
+//! - [`Disjoint<'_, Keys>`](Disjoint) is implemented on `S` when it contains every type in
+//! `Keys`, allowing:
+//!     - [`get_disjoint_mut`](TyghtMap::get_disjoint_mut)
+//! - [`Update<Old, New>`](Update) is implemented on `S` when it contains `Old` and no other
+//! element of type `New`, allowing:
+//!     - [`update`](TyghtMap::update)
+//! - [`Merge<Rhs, Policy>`](Merge) generalizes [`Union`] with a type-level
+//! [`MergePolicy`] ([`PreferLeft`]/[`PreferRight`]) choosing the winner on overlap, allowing:
+//!     - [`merge_with`](TyghtMap::merge_with)
 //!
 //! The following function _cannot_ be called using a map which does not contain a `String` and a `u32`.
 //!
@@ -85,6 +114,19 @@
 //! }
 //! ```
 //!
+//! # `alloc`
+//!
+//! The `alloc` feature enables [`HybridMap`], which pairs a [`TyghtMap`] with a dynamic,
+//! `TypeId`-keyed fallback store for types that are only known at runtime. The static half keeps
+//! this crate's usual zero-overhead guarantees; the dynamic half trades those for a heap
+//! allocation and a runtime lookup per entry.
+//!
+//! # `serde`
+//!
+//! The `serde` feature implements [`Serialize`](serde::Serialize)/[`Deserialize`](serde::Deserialize)
+//! for [`TyghtMap`], representing it as a map keyed by [`core::any::type_name`]. Deserialization
+//! fails if a key is unknown, duplicated, or missing.
+//!
 //! # Nightly
 //!
 //! In contrast to other attempts, this implementation does not rely on specialization. It does however rely on a
@@ -99,8 +141,20 @@
 //!
 
 mod contains;
+mod disjoint;
+mod entry;
+mod for_each;
+#[cfg(feature = "alloc")]
+mod hybrid;
 mod maybe_contains;
+mod merge;
 mod missing;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod split;
+mod union;
+mod update;
+mod visit;
 
 /// Represents the empty set.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -115,8 +169,18 @@ pub struct Cons<H, T> {
 }
 
 pub use contains::Contains;
+pub use disjoint::Disjoint;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use for_each::ForEach;
+#[cfg(feature = "alloc")]
+pub use hybrid::HybridMap;
 pub use maybe_contains::MaybeContains;
+pub use merge::{Merge, MergePolicy, PreferLeft, PreferRight};
 pub use missing::Missing;
+pub use split::{Project, Split};
+pub use union::Union;
+pub use update::Update;
+pub use visit::{FnVisitor, Visitor, VisitorMut, Walk};
 
 /// A static type map.
 ///
@@ -223,6 +287,173 @@ impl<S> TyghtMap<S> {
         let (item, map) = self.0.try_remove();
         (item, TyghtMap(map))
     }
+
+    /// Keeps the existing value for `T`, or inserts the result of `f` if absent.
+    ///
+    /// This consumes the map and returns the post-insertion map. Unlike a classic `Entry` API,
+    /// this cannot hand back a reference to the value alongside the map: inserting may change
+    /// the map's type, so there is no single map for a returned reference to borrow from. Use
+    /// [`get`](TyghtMap::get) on the returned map to retrieve the value.
+    ///
+    /// ```
+    /// # use tyght_map::*;
+    /// let map = TyghtMap::new().get_or_insert_with(|| 1_u32);
+    /// assert_eq!(*map.get::<u32>(), 1);
+    ///
+    /// let map = map.get_or_insert_with(|| 2_u32);
+    /// assert_eq!(*map.get::<u32>(), 1);
+    /// ```
+    pub fn get_or_insert_with<T>(self, f: impl FnOnce() -> T) -> TyghtMap<S::Inserted>
+    where
+        S: MaybeContains<T>,
+    {
+        TyghtMap(self.0.get_or_insert_with(f))
+    }
+
+    /// Merges `other` into `self`, deduplicating types present in both maps.
+    ///
+    /// When a type is present in both maps, the value from `self` is kept.
+    pub fn merge<S2>(self, other: TyghtMap<S2>) -> TyghtMap<S::Output>
+    where
+        S: Union<S2>,
+    {
+        TyghtMap(self.0.union(other.0))
+    }
+
+    /// Merges `other` into `self`, deduplicating types present in both maps.
+    ///
+    /// When a type is present in both maps, the value from `other` is kept.
+    pub fn merge_replace<S2>(self, other: TyghtMap<S2>) -> TyghtMap<<S2 as Union<S>>::Output>
+    where
+        S2: Union<S>,
+    {
+        TyghtMap(other.0.union(self.0))
+    }
+
+    /// Calls `f` with every element, coerced to `&Dyn`.
+    ///
+    /// ```
+    /// # use core::fmt::Debug;
+    /// # use tyght_map::*;
+    /// let map = TyghtMap::new().insert(1_u32).insert("hey");
+    /// map.for_each::<dyn Debug>(|x| println!("{x:?}"));
+    /// ```
+    pub fn for_each<Dyn: ?Sized>(&self, mut f: impl FnMut(&Dyn))
+    where
+        S: ForEach<Dyn>,
+    {
+        self.0.for_each(&mut f)
+    }
+
+    /// Calls `f` with every element, coerced to `&mut Dyn`.
+    pub fn for_each_mut<Dyn: ?Sized>(&mut self, mut f: impl FnMut(&mut Dyn))
+    where
+        S: ForEach<Dyn>,
+    {
+        self.0.for_each_mut(&mut f)
+    }
+
+    /// Returns an [`OccupiedEntry`] or [`VacantEntry`] for `T`, resolved at compile time.
+    ///
+    /// ```
+    /// # use tyght_map::*;
+    /// let map = TyghtMap::new().entry::<u32>().or_insert(0);
+    /// assert_eq!(0, *map.get::<u32>());
+    /// ```
+    pub fn entry<T>(self) -> <S as Entry<T>>::EntryType
+    where
+        S: Entry<T>,
+    {
+        self.0.entry()
+    }
+
+    /// Visits every element by shared reference, in map order.
+    pub fn walk<V: Visitor>(&self, visitor: &mut V)
+    where
+        S: Walk,
+    {
+        self.0.walk(visitor)
+    }
+
+    /// Visits every element by mutable reference, in map order.
+    pub fn walk_mut<V: VisitorMut>(&mut self, visitor: &mut V)
+    where
+        S: Walk,
+    {
+        self.0.walk_mut(visitor)
+    }
+
+    /// Visits every element by value, consuming the map.
+    pub fn walk_into<V: FnVisitor>(self, visitor: &mut V)
+    where
+        S: Walk,
+    {
+        self.0.walk_into(visitor)
+    }
+
+    /// Splits a unique reference to the map into disjoint mutable references, one for each type
+    /// listed in `Keys`.
+    ///
+    /// ```
+    /// # use tyght_map::*;
+    /// let mut map = TyghtMap::new().insert(1_u8).insert(2_u16).insert(3_u32);
+    /// let _refs: Cons<&mut u32, Cons<&mut u8, Nil>> = map.get_disjoint_mut();
+    /// ```
+    pub fn get_disjoint_mut<'a, Keys>(&'a mut self) -> <S as Disjoint<'a, Keys>>::Output
+    where
+        S: Disjoint<'a, Keys>,
+    {
+        self.0.disjoint()
+    }
+
+    /// Carves the types listed in `Sub` out of the map, returning them alongside the remainder.
+    pub fn split<Sub>(self) -> (TyghtMap<Sub>, TyghtMap<S::Rest>)
+    where
+        S: Split<Sub>,
+    {
+        let (sub, rest) = self.0.split();
+        (TyghtMap(sub), TyghtMap(rest))
+    }
+
+    /// Borrows a reference to every type listed in `Sub`, without consuming the map.
+    pub fn project<'a, Sub>(&'a self) -> TyghtMap<<S as Project<'a, Sub>>::Output>
+    where
+        S: Project<'a, Sub>,
+    {
+        TyghtMap(self.0.project())
+    }
+
+    /// Replaces the value of type `Old` with `f`'s result, keeping its position in the map.
+    ///
+    /// ```
+    /// # use tyght_map::*;
+    /// let map = TyghtMap::new().insert(1_u8).insert(2_u16).insert(3_u32);
+    /// let map = map.update::<u16, _>(|x| x * 10);
+    /// assert_eq!(20_u16, *map.get::<u16>());
+    /// ```
+    pub fn update<Old, New>(self, f: impl FnOnce(Old) -> New) -> TyghtMap<S::Output>
+    where
+        S: Update<Old, New>,
+    {
+        TyghtMap(self.0.update(f))
+    }
+
+    /// Merges `other` into `self` according to `Policy`, deduplicating types present in both
+    /// maps.
+    ///
+    /// ```
+    /// # use tyght_map::*;
+    /// let a = TyghtMap::new().insert(1_u8).insert(2_u16);
+    /// let b = TyghtMap::new().insert(3_u16).insert(4_u32);
+    /// let merged = a.merge_with::<_, PreferRight>(b);
+    /// assert_eq!(3_u16, *merged.get());
+    /// ```
+    pub fn merge_with<S2, Policy>(self, other: TyghtMap<S2>) -> TyghtMap<S::Output>
+    where
+        S: Merge<S2, Policy>,
+    {
+        TyghtMap(self.0.merge_with(other.0))
+    }
 }
 
 #[cfg(test)]
@@ -285,4 +516,121 @@ mod tests {
 
         assert_eq!(map, TyghtMap::new());
     }
+
+    #[test]
+    fn get_or_insert_with() {
+        let map = TyghtMap::new().get_or_insert_with(|| 1_u32);
+        assert_eq!(1_u32, *map.get());
+
+        let map = map.get_or_insert_with(|| 2_u32);
+        assert_eq!(1_u32, *map.get());
+    }
+
+    #[test]
+    fn merge() {
+        let a = TyghtMap::new().insert(1_u8).insert(2_u16);
+        let b = TyghtMap::new().insert(3_u16).insert(4_u32);
+
+        let merged = a.merge(b);
+        assert_eq!(1_u8, *merged.get());
+        assert_eq!(2_u16, *merged.get());
+        assert_eq!(4_u32, *merged.get());
+    }
+
+    #[test]
+    fn merge_replace() {
+        let a = TyghtMap::new().insert(1_u8).insert(2_u16);
+        let b = TyghtMap::new().insert(3_u16).insert(4_u32);
+
+        let merged = a.merge_replace(b);
+        assert_eq!(1_u8, *merged.get());
+        assert_eq!(3_u16, *merged.get());
+        assert_eq!(4_u32, *merged.get());
+    }
+
+    #[test]
+    fn for_each() {
+        let map = TyghtMap::new().insert(1_u8).insert(2_u16).insert(3_u32);
+
+        let mut count = 0;
+        map.for_each::<dyn core::fmt::Debug>(|_| count += 1);
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn split_and_project() {
+        let map = TyghtMap::new().insert(1_u8).insert(2_u16).insert(3_u32);
+
+        let projected: TyghtMap<Cons<&u16, Nil>> = map.project::<Cons<u16, Nil>>();
+        assert_eq!(2_u16, *projected.get());
+
+        let (sub, rest): (TyghtMap<Cons<u16, Nil>>, _) = map.split();
+        assert_eq!(2_u16, *sub.get());
+        assert_eq!(1_u8, *rest.get());
+        assert_eq!(3_u32, *rest.get());
+    }
+
+    #[test]
+    fn entry() {
+        let map = TyghtMap::new().entry::<u32>().or_insert(0);
+        let map = map.entry::<u32>().and_modify(|x| *x += 1).or_insert(0);
+        assert_eq!(1_u32, *map.get());
+    }
+
+    #[test]
+    fn walk() {
+        struct Counter(usize);
+        impl Visitor for Counter {
+            fn visit<T: 'static>(&mut self, _value: &T) {
+                self.0 += 1;
+            }
+        }
+
+        let map = TyghtMap::new().insert(1_u8).insert(2_u16).insert(3_u32);
+
+        let mut counter = Counter(0);
+        map.walk(&mut counter);
+        assert_eq!(3, counter.0);
+    }
+
+    #[test]
+    fn get_disjoint_mut() {
+        let mut map = TyghtMap::new().insert(1_u8).insert(2_u16).insert(3_u32);
+
+        let disjoint: Cons<&mut u32, Cons<&mut u8, Nil>> = map.get_disjoint_mut();
+        *disjoint.head += 10;
+        *disjoint.tail.head += 1;
+
+        assert_eq!(2_u8, *map.get::<u8>());
+        assert_eq!(13_u32, *map.get::<u32>());
+    }
+
+    #[test]
+    fn update() {
+        let map = TyghtMap::new().insert(1_u8).insert(2_u16).insert(3_u32);
+
+        let map = map.update::<u16, _>(|x| x * 10);
+        assert_eq!(1_u8, *map.get::<u8>());
+        assert_eq!(20_u16, *map.get::<u16>());
+        assert_eq!(3_u32, *map.get::<u32>());
+    }
+
+    #[test]
+    fn merge_with() {
+        let a = TyghtMap::new().insert(1_u8).insert(2_u16);
+        let b = TyghtMap::new().insert(3_u16).insert(4_u32);
+
+        let merged = a.merge_with::<_, PreferLeft>(b);
+        assert_eq!(1_u8, *merged.get());
+        assert_eq!(2_u16, *merged.get());
+        assert_eq!(4_u32, *merged.get());
+
+        let a = TyghtMap::new().insert(1_u8).insert(2_u16);
+        let b = TyghtMap::new().insert(3_u16).insert(4_u32);
+
+        let merged = a.merge_with::<_, PreferRight>(b);
+        assert_eq!(1_u8, *merged.get());
+        assert_eq!(3_u16, *merged.get());
+        assert_eq!(4_u32, *merged.get());
+    }
 }
@@ -15,6 +15,8 @@ pub trait LocalMaybeContains<Item, const HEAD: bool> {
     fn try_remove(self) -> (Option<Item>, Self::Removed);
 
     fn try_insert(self, value: Item) -> (Option<Item>, Self::Inserted);
+
+    fn get_or_insert_with(self, f: impl FnOnce() -> Item) -> Self::Inserted;
 }
 
 impl<Item> LocalMaybeContains<Item, true> for Nil
@@ -41,6 +43,13 @@ where
     fn try_insert(self, head: Item) -> (Option<Item>, Self::Inserted) {
         (None, Cons { head, tail: self })
     }
+
+    fn get_or_insert_with(self, f: impl FnOnce() -> Item) -> Self::Inserted {
+        Cons {
+            head: f(),
+            tail: self,
+        }
+    }
 }
 
 impl<Item, T> LocalMaybeContains<Item, true> for Cons<Item, T>
@@ -69,6 +78,10 @@ where
         let Self { head, tail } = self;
         (Some(head), Cons { head: item, tail })
     }
+
+    fn get_or_insert_with(self, _f: impl FnOnce() -> Item) -> Self::Inserted {
+        self
+    }
 }
 
 impl<Item, H, T> LocalMaybeContains<Item, false> for Cons<H, T>
@@ -108,6 +121,14 @@ where
         };
         (item, inserted)
     }
+
+    fn get_or_insert_with(self, f: impl FnOnce() -> Item) -> Self::Inserted {
+        let Self { head, tail } = self;
+        Cons {
+            head,
+            tail: tail.get_or_insert_with(f),
+        }
+    }
 }
 
 /// A trait marking whether `T` is maybe present.
@@ -133,6 +154,9 @@ pub trait MaybeContains<Item> {
 
     #[doc(hidden)]
     fn try_remove(self) -> (Option<Item>, Self::Removed);
+
+    #[doc(hidden)]
+    fn get_or_insert_with(self, f: impl FnOnce() -> Item) -> Self::Inserted;
 }
 
 impl<Item> MaybeContains<Item> for Nil {
@@ -156,6 +180,10 @@ impl<Item> MaybeContains<Item> for Nil {
     fn try_remove(self) -> (Option<Item>, Self::Removed) {
         (None, Nil)
     }
+
+    fn get_or_insert_with(self, f: impl FnOnce() -> Item) -> Self::Inserted {
+        Cons { head: f(), tail: self }
+    }
 }
 
 impl<Item, H, T> MaybeContains<Item> for Cons<H, T>
@@ -193,6 +221,12 @@ where
             self,
         )
     }
+
+    fn get_or_insert_with(self, f: impl FnOnce() -> Item) -> Self::Inserted {
+        <Self as LocalMaybeContains<Item, { type_id::<Item>() == type_id::<H>() }>>::get_or_insert_with(
+            self, f,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,123 @@
+use crate::Union;
+
+/// A [`MergePolicy`] preferring the left-hand map's value on overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PreferLeft;
+
+/// A [`MergePolicy`] preferring the right-hand map's value on overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PreferRight;
+
+/// A trait resolving which side of a merge wins on overlapping types.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait MergePolicy<S, S2> {
+    /// The combined set, with types common to both sides deduplicated.
+    type Output;
+
+    /// Combines `left` with `right` according to the policy.
+    fn resolve(left: S, right: S2) -> Self::Output;
+}
+
+impl<S, S2> MergePolicy<S, S2> for PreferLeft
+where
+    S: Union<S2>,
+{
+    type Output = S::Output;
+
+    fn resolve(left: S, right: S2) -> Self::Output {
+        left.union(right)
+    }
+}
+
+impl<S, S2> MergePolicy<S, S2> for PreferRight
+where
+    S2: Union<S>,
+{
+    type Output = S2::Output;
+
+    fn resolve(left: S, right: S2) -> Self::Output {
+        right.union(left)
+    }
+}
+
+/// A trait marking that `Self` can be merged with `Rhs` according to `Policy`.
+///
+/// This generalizes [`Union`] by making which side wins on overlap a type-level parameter
+/// ([`PreferLeft`] or [`PreferRight`]) rather than which operand is `self`.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Merge<Rhs, Policy> {
+    /// The combined set, with types common to both sides deduplicated.
+    type Output;
+
+    /// Combines `self` with `rhs` according to `Policy`.
+    fn merge_with(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<S, Rhs, Policy> Merge<Rhs, Policy> for S
+where
+    Policy: MergePolicy<S, Rhs>,
+{
+    type Output = Policy::Output;
+
+    fn merge_with(self, rhs: Rhs) -> Self::Output {
+        Policy::resolve(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cons, MaybeContains, Nil};
+
+    type Left = Cons<u8, Cons<u16, Nil>>;
+    type Right = Cons<u16, Cons<u32, Nil>>;
+
+    static_assertions::assert_impl_all!(Left: Merge<Right, PreferLeft>);
+    static_assertions::assert_impl_all!(Left: Merge<Right, PreferRight>);
+
+    #[test]
+    fn prefer_left() {
+        let left: Left = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Nil,
+            },
+        };
+        let right: Right = Cons {
+            head: 3_u16,
+            tail: Cons {
+                head: 4_u32,
+                tail: Nil,
+            },
+        };
+
+        let merged = Merge::<_, PreferLeft>::merge_with(left, right);
+        assert_eq!(Some(&2_u16), merged.try_get());
+    }
+
+    #[test]
+    fn prefer_right() {
+        let left: Left = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Nil,
+            },
+        };
+        let right: Right = Cons {
+            head: 3_u16,
+            tail: Cons {
+                head: 4_u32,
+                tail: Nil,
+            },
+        };
+
+        let merged = Merge::<_, PreferRight>::merge_with(left, right);
+        assert_eq!(Some(&3_u16), merged.try_get());
+    }
+}
@@ -0,0 +1,187 @@
+extern crate alloc;
+
+use alloc::string::String;
+use core::{any::type_name, fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, MapAccess, Visitor as DeVisitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Cons, ForEach, Nil, TyghtMap};
+
+/// An object-safe counterpart to [`Serialize`], carrying its own `type_name` alongside the
+/// value, so a [`ForEach`]-driven traversal can key each entry without knowing the concrete
+/// element type.
+///
+/// [`ForEach`] is used here rather than [`Walk`](crate::Walk): [`Walk`]'s [`Visitor`](crate::Visitor)
+/// is generic over any `T: 'static`, with no way to additionally require `T: Serialize` at the
+/// call site, whereas [`ForEach`] already coerces each element to a trait object, which is
+/// exactly the hook needed to serialize it.
+#[doc(hidden)]
+pub trait NamedSerialize: erased_serde::Serialize {
+    fn type_name(&self) -> &'static str;
+}
+
+impl<T> NamedSerialize for T
+where
+    T: Serialize + 'static,
+{
+    fn type_name(&self) -> &'static str {
+        type_name::<T>()
+    }
+}
+
+impl<S> Serialize for TyghtMap<S>
+where
+    S: ForEach<dyn NamedSerialize>,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        let mut error = None;
+        self.for_each::<dyn NamedSerialize>(|item| {
+            if error.is_some() {
+                return;
+            }
+            let value: &dyn erased_serde::Serialize = item;
+            if let Err(e) = map.serialize_entry(item.type_name(), value) {
+                error = Some(e);
+            }
+        });
+        if let Some(e) = error {
+            return Err(e);
+        }
+        map.end()
+    }
+}
+
+/// A trait building `Self` up from entries keyed by `core::any::type_name`, erroring on unknown,
+/// duplicate or missing keys.
+#[doc(hidden)]
+pub trait DeserializeEntries<'de>: Sized {
+    /// A partially-built accumulator, one slot per element, empty until filled.
+    type Builder: Default;
+
+    /// Fills the slot whose type name matches `key`, if any; returns `false` if no slot matches.
+    fn fill<A>(builder: &mut Self::Builder, key: &str, map: &mut A) -> Result<bool, A::Error>
+    where
+        A: MapAccess<'de>;
+
+    /// Finalizes the builder, erroring if any slot was left unfilled.
+    fn finish<E: de::Error>(builder: Self::Builder) -> Result<Self, E>;
+}
+
+impl<'de> DeserializeEntries<'de> for Nil {
+    type Builder = ();
+
+    fn fill<A>(_builder: &mut Self::Builder, _key: &str, _map: &mut A) -> Result<bool, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        Ok(false)
+    }
+
+    fn finish<E: de::Error>(_builder: Self::Builder) -> Result<Self, E> {
+        Ok(Nil)
+    }
+}
+
+impl<'de, H, T> DeserializeEntries<'de> for Cons<H, T>
+where
+    H: Deserialize<'de> + 'static,
+    T: DeserializeEntries<'de>,
+{
+    type Builder = (Option<H>, T::Builder);
+
+    fn fill<A>(builder: &mut Self::Builder, key: &str, map: &mut A) -> Result<bool, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        if key == type_name::<H>() {
+            if builder.0.is_some() {
+                return Err(de::Error::custom(format_args!("duplicate key `{key}`")));
+            }
+            builder.0 = Some(map.next_value()?);
+            Ok(true)
+        } else {
+            T::fill(&mut builder.1, key, map)
+        }
+    }
+
+    fn finish<E: de::Error>(builder: Self::Builder) -> Result<Self, E> {
+        let (head, tail) = builder;
+        let head = head.ok_or_else(|| de::Error::custom(format_args!("missing key `{}`", type_name::<H>())))?;
+        Ok(Cons {
+            head,
+            tail: T::finish(tail)?,
+        })
+    }
+}
+
+struct TyghtMapVisitor<S>(PhantomData<S>);
+
+impl<'de, S> DeVisitor<'de> for TyghtMapVisitor<S>
+where
+    S: DeserializeEntries<'de>,
+{
+    type Value = TyghtMap<S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map keyed by type name")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut builder = S::Builder::default();
+        while let Some(key) = map.next_key::<String>()? {
+            if !S::fill(&mut builder, &key, &mut map)? {
+                return Err(de::Error::custom(format_args!("unknown key `{key}`")));
+            }
+        }
+        Ok(TyghtMap(S::finish(builder)?))
+    }
+}
+
+impl<'de, S> Deserialize<'de> for TyghtMap<S>
+where
+    S: DeserializeEntries<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(TyghtMapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let map = TyghtMap::new().insert(1_u32).insert("hey".to_string());
+
+        let json = serde_json::to_string(&map).unwrap();
+        let roundtripped: TyghtMap<Cons<u32, Cons<String, Nil>>> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(map, roundtripped);
+    }
+
+    #[test]
+    fn unknown_key_errors() {
+        let json = r#"{"i32": 1}"#;
+        let result: Result<TyghtMap<Cons<u32, Nil>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn missing_key_errors() {
+        let json = "{}";
+        let result: Result<TyghtMap<Cons<u32, Nil>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{contains::Contains, Cons, Nil};
+
+/// A trait marking that `Sub` can be carved out of `Self`, leaving `Self::Rest` behind.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Split<Sub> {
+    /// The remainder left behind once `Sub` has been removed.
+    type Rest;
+
+    /// Moves the types listed in `Sub` out of `self`, returning them alongside the remainder.
+    fn split(self) -> (Sub, Self::Rest);
+}
+
+impl<S> Split<Nil> for S {
+    type Rest = S;
+
+    fn split(self) -> (Nil, Self::Rest) {
+        (Nil, self)
+    }
+}
+
+impl<H, SubT, S> Split<Cons<H, SubT>> for S
+where
+    S: Contains<H>,
+    S::Removed: Split<SubT>,
+{
+    type Rest = <S::Removed as Split<SubT>>::Rest;
+
+    fn split(self) -> (Cons<H, SubT>, Self::Rest) {
+        let (head, removed) = self.remove();
+        let (tail, rest) = removed.split();
+        (Cons { head, tail }, rest)
+    }
+}
+
+/// A trait marking that a reference to every type in `Sub` can be borrowed from `Self`.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Project<'a, Sub> {
+    /// The borrowed projection, a cons-list of the same shape as `Sub` but holding `&'a` items.
+    type Output;
+
+    /// Borrows a reference to every type listed in `Sub`.
+    fn project(&'a self) -> Self::Output;
+}
+
+impl<'a, S> Project<'a, Nil> for S {
+    type Output = Nil;
+
+    fn project(&'a self) -> Self::Output {
+        Nil
+    }
+}
+
+impl<'a, H, SubT, S> Project<'a, Cons<H, SubT>> for S
+where
+    H: 'static,
+    S: Contains<H>,
+    S: Project<'a, SubT>,
+{
+    type Output = Cons<&'a H, <S as Project<'a, SubT>>::Output>;
+
+    fn project(&'a self) -> Self::Output {
+        Cons {
+            head: self.get(),
+            tail: Project::<'a, SubT>::project(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ty = Cons<u8, Cons<u16, Cons<u32, Nil>>>;
+    type Sub = Cons<u16, Cons<u8, Nil>>;
+
+    static_assertions::assert_impl_all!(Ty: Split<Sub>);
+
+    #[test]
+    fn split() {
+        let map: Ty = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Cons {
+                    head: 3_u32,
+                    tail: Nil,
+                },
+            },
+        };
+
+        let (sub, rest): (Sub, _) = map.split();
+        assert_eq!(2_u16, sub.head);
+        assert_eq!(1_u8, sub.tail.head);
+        assert_eq!(3_u32, rest.head);
+    }
+
+    #[test]
+    fn project() {
+        let map: Ty = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Cons {
+                    head: 3_u32,
+                    tail: Nil,
+                },
+            },
+        };
+
+        let projected: Cons<&u16, Cons<&u8, Nil>> = Project::<Sub>::project(&map);
+        assert_eq!(&2_u16, projected.head);
+        assert_eq!(&1_u8, projected.tail.head);
+    }
+}
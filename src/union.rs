@@ -0,0 +1,68 @@
+use crate::{maybe_contains::MaybeContains, Cons, Nil};
+
+/// A trait marking the type-level union of `Self` and `Rhs`.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Union<Rhs> {
+    /// The combined set, with types common to both sides deduplicated.
+    type Output;
+
+    /// Combines `self` with `rhs`, keeping the left-hand (`self`) value on overlap.
+    fn union(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<Rhs> Union<Rhs> for Nil {
+    type Output = Rhs;
+
+    fn union(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<H, T, Rhs> Union<Rhs> for Cons<H, T>
+where
+    H: 'static,
+    T: Union<Rhs>,
+    T::Output: MaybeContains<H>,
+{
+    type Output = <T::Output as MaybeContains<H>>::Inserted;
+
+    fn union(self, rhs: Rhs) -> Self::Output {
+        let Self { head, tail } = self;
+        let (_, inserted) = tail.union(rhs).try_insert(head);
+        inserted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Left = Cons<u8, Cons<u16, Nil>>;
+    type Right = Cons<u16, Cons<u32, Nil>>;
+
+    static_assertions::assert_impl_all!(Left: Union<Right>);
+
+    #[test]
+    fn union_left_wins() {
+        let left: Left = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Nil,
+            },
+        };
+        let right: Right = Cons {
+            head: 3_u16,
+            tail: Cons {
+                head: 4_u32,
+                tail: Nil,
+            },
+        };
+
+        let merged = left.union(right);
+        assert_eq!(Some(&1_u8), merged.try_get());
+        assert_eq!(Some(&2_u16), merged.try_get());
+        assert_eq!(Some(&4_u32), merged.try_get());
+    }
+}
@@ -0,0 +1,107 @@
+use core::intrinsics::type_id;
+
+use crate::{contains::Contains, maybe_contains::MaybeContains, missing::Missing, Cons, Nil};
+
+#[doc(hidden)]
+pub trait LocalUpdate<Old, New, const HEAD: bool> {
+    type Output;
+
+    fn update(self, f: impl FnOnce(Old) -> New) -> Self::Output;
+}
+
+impl<Old, New, T> LocalUpdate<Old, New, true> for Cons<Old, T>
+where
+    Old: 'static,
+{
+    type Output = Cons<New, T>;
+
+    fn update(self, f: impl FnOnce(Old) -> New) -> Self::Output {
+        let Self { head, tail } = self;
+        Cons {
+            head: f(head),
+            tail,
+        }
+    }
+}
+
+impl<Old, New, H, T> LocalUpdate<Old, New, false> for Cons<H, T>
+where
+    Old: 'static,
+    T: Update<Old, New>,
+{
+    type Output = Cons<H, T::Output>;
+
+    fn update(self, f: impl FnOnce(Old) -> New) -> Self::Output {
+        let Self { head, tail } = self;
+        Cons {
+            head,
+            tail: tail.update(f),
+        }
+    }
+}
+
+/// A trait replacing the value of type `Old` in `Self` with one of type `New`, in place, without
+/// disturbing the position of any other element.
+///
+/// Unlike [`remove`](crate::TyghtMap::remove) followed by [`insert`](crate::TyghtMap::insert),
+/// which always appends the new value at the tail, this keeps the element at the same index it
+/// occupied before the update.
+///
+/// `New` must not already occur elsewhere in `Self`, mirroring the [`Missing`] bound on
+/// [`insert`](crate::TyghtMap::insert): otherwise the map would end up with two slots of the
+/// same type, and the original value behind the first match would become unreachable.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Update<Old, New> {
+    /// `Self` with `Old` replaced by `New`, at the same position.
+    type Output;
+
+    /// Replaces the value of type `Old` with `f`'s result, keeping its position.
+    fn update(self, f: impl FnOnce(Old) -> New) -> Self::Output;
+}
+
+impl<Old, New, H, T> Update<Old, New> for Cons<H, T>
+where
+    Old: 'static,
+    H: 'static,
+    Self: Contains<Old>,
+    Self: LocalUpdate<Old, New, { type_id::<Old>() == type_id::<H>() }>,
+    <Self as MaybeContains<Old>>::Removed: Missing<New>,
+{
+    type Output = <Self as LocalUpdate<Old, New, { type_id::<Old>() == type_id::<H>() }>>::Output;
+
+    fn update(self, f: impl FnOnce(Old) -> New) -> Self::Output {
+        <Self as LocalUpdate<Old, New, { type_id::<Old>() == type_id::<H>() }>>::update(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ty = Cons<u8, Cons<u16, Cons<u32, Nil>>>;
+
+    static_assertions::assert_impl_all!(Ty: Update<u16, &'static str, Output = Cons<u8, Cons<&'static str, Cons<u32, Nil>>>>);
+
+    // Updating `u16` to `u32` would leave two `u32` slots, so this must not type-check.
+    static_assertions::assert_not_impl_any!(Ty: Update<u16, u32>);
+
+    #[test]
+    fn update_preserves_position() {
+        let map: Ty = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Cons {
+                    head: 3_u32,
+                    tail: Nil,
+                },
+            },
+        };
+
+        let updated = Update::<u16, _>::update(map, |x| x * 10);
+        assert_eq!(1_u8, updated.head);
+        assert_eq!(20_u16, updated.tail.head);
+        assert_eq!(3_u32, updated.tail.tail.head);
+    }
+}
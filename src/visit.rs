@@ -0,0 +1,120 @@
+use crate::{Cons, Nil};
+
+/// Visits every element of a map by shared reference.
+pub trait Visitor {
+    /// Called once per element, in map order.
+    fn visit<T: 'static>(&mut self, value: &T);
+}
+
+/// Visits every element of a map by mutable reference.
+pub trait VisitorMut {
+    /// Called once per element, in map order.
+    fn visit_mut<T: 'static>(&mut self, value: &mut T);
+}
+
+/// Visits every element of a map by value, consuming it.
+pub trait FnVisitor {
+    /// Called once per element, in map order.
+    fn visit<T: 'static>(&mut self, value: T);
+}
+
+/// A trait walking every element of `Self`, handing each to a [`Visitor`], [`VisitorMut`] or
+/// [`FnVisitor`] in turn.
+///
+/// This is the generic counterpart to [`ForEach`](crate::ForEach): it doesn't require a common
+/// `Dyn` trait every element coerces to, only that the visitor itself is generic over `T`.
+///
+/// See [Traits](crate#traits) section of crate documentation for more information.
+pub trait Walk {
+    /// Visits every element by shared reference.
+    fn walk<V: Visitor>(&self, visitor: &mut V);
+
+    /// Visits every element by mutable reference.
+    fn walk_mut<V: VisitorMut>(&mut self, visitor: &mut V);
+
+    /// Visits every element by value, consuming `self`.
+    fn walk_into<V: FnVisitor>(self, visitor: &mut V);
+}
+
+impl Walk for Nil {
+    fn walk<V: Visitor>(&self, _visitor: &mut V) {}
+
+    fn walk_mut<V: VisitorMut>(&mut self, _visitor: &mut V) {}
+
+    fn walk_into<V: FnVisitor>(self, _visitor: &mut V) {}
+}
+
+impl<H, T> Walk for Cons<H, T>
+where
+    H: 'static,
+    T: Walk,
+{
+    fn walk<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit(&self.head);
+        self.tail.walk(visitor);
+    }
+
+    fn walk_mut<V: VisitorMut>(&mut self, visitor: &mut V) {
+        visitor.visit_mut(&mut self.head);
+        self.tail.walk_mut(visitor);
+    }
+
+    fn walk_into<V: FnVisitor>(self, visitor: &mut V) {
+        let Self { head, tail } = self;
+        visitor.visit(head);
+        tail.walk_into(visitor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Ty = Cons<u8, Cons<u16, Cons<u32, Nil>>>;
+
+    static_assertions::assert_impl_all!(Ty: Walk);
+
+    struct Counter(usize);
+
+    impl Visitor for Counter {
+        fn visit<T: 'static>(&mut self, _value: &T) {
+            self.0 += 1;
+        }
+    }
+
+    impl VisitorMut for Counter {
+        fn visit_mut<T: 'static>(&mut self, _value: &mut T) {
+            self.0 += 1;
+        }
+    }
+
+    impl FnVisitor for Counter {
+        fn visit<T: 'static>(&mut self, _value: T) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn walk() {
+        let mut map: Ty = Cons {
+            head: 1_u8,
+            tail: Cons {
+                head: 2_u16,
+                tail: Cons {
+                    head: 3_u32,
+                    tail: Nil,
+                },
+            },
+        };
+
+        let mut counter = Counter(0);
+        map.walk(&mut counter);
+        assert_eq!(3, counter.0);
+
+        map.walk_mut(&mut counter);
+        assert_eq!(6, counter.0);
+
+        map.walk_into(&mut counter);
+        assert_eq!(9, counter.0);
+    }
+}